@@ -1,12 +1,17 @@
 use std::{marker::PhantomData, time::Duration};
 
 use async_openai::{config::OpenAIConfig, Client};
-use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
+use futures::future::BoxFuture;
+use serde::Serialize;
+use sqlx::{postgres::PgPoolOptions, Pool, Postgres, Transaction};
+use uuid::Uuid;
 
 use crate::{
     errors::sdk::SDKError,
+    jobs::queue::JobQueue,
+    members::{operations::get_member_with, password},
     organization::operations::{
-        CreateOrganizationInput, Organization, OrganizationCrudOperations, SetOrganizationInputBuilder,
+        set_organization_setting_with, CreateOrganizationInput, Organization, SetOrganizationInputBuilder,
         GLOBAL_ORGANIZATION_SETTINGS_NAME,
     },
 };
@@ -51,6 +56,10 @@ pub struct Engine<State: EngineState> {
 }
 
 impl Engine<WithoutContext> {
+    /// Seeds an organization's settings row. Runs through [`Engine::transaction`] so this stays
+    /// atomic as it grows to also seed the owner's default member row and any other
+    /// organization-scoped state that must succeed or fail together.
+    #[tracing::instrument(skip(self, ctx, value), fields(member_id = %ctx.member_id), err)]
     pub async fn initialize_organization(
         &self,
         ctx: &EngineContext,
@@ -58,15 +67,15 @@ impl Engine<WithoutContext> {
     ) -> Result<Organization, SDKError> {
         let org_serialized = serde_json::to_string(&value)?;
 
+        let input = SetOrganizationInputBuilder::default()
+            .owner_id(ctx.member_id)
+            .name(GLOBAL_ORGANIZATION_SETTINGS_NAME.to_string())
+            .value(org_serialized)
+            .build()
+            .unwrap();
+
         let org = self
-            .set_organization_setting(
-                SetOrganizationInputBuilder::default()
-                    .owner_id(ctx.member_id)
-                    .name(GLOBAL_ORGANIZATION_SETTINGS_NAME.to_string())
-                    .value(org_serialized)
-                    .build()
-                    .unwrap(),
-            )
+            .transaction(|tx| Box::pin(async move { set_organization_setting_with(tx, input).await }))
             .await?;
 
         Ok(org.into())
@@ -84,6 +93,9 @@ where
             .await
     }
 
+    // `config` holds the database DSN and the LLM API key, both secrets, so it's fully skipped
+    // rather than partially recorded.
+    #[tracing::instrument(skip(config), err)]
     pub async fn new_without_context(config: SDKConfig) -> Result<Engine<WithoutContext>, SDKError> {
         let pool = PgPoolOptions::new()
             .max_connections(10)
@@ -106,8 +118,10 @@ where
         })
     }
 
+    #[tracing::instrument(skip(self), err)]
     pub async fn migrate(&self) -> Result<(), SDKError> {
         sqlx::migrate!().run(self.db_pool.as_ref()).await?;
+        tracing::info!("migrations applied");
         Ok(())
     }
 
@@ -117,9 +131,72 @@ where
             None => Err(SDKError::VersionNotFound),
         }
     }
+
+    /// Returns a [`JobQueue`] handle backed by this engine's pool, for enqueueing or a
+    /// [`crate::jobs::worker::Worker`] to poll.
+    pub fn jobs(&self) -> JobQueue {
+        JobQueue::new(self.db_pool.as_ref().clone())
+    }
+
+    /// Enqueues out-of-band work (e.g. an LLM generation) onto `queue` instead of running it
+    /// inline on the request path. Returns the new job's id.
+    #[tracing::instrument(skip(self, payload), fields(queue = %queue), err)]
+    pub async fn enqueue_job<P: Serialize + Send + Sync>(&self, queue: &str, payload: &P) -> Result<Uuid, SDKError> {
+        self.jobs().enqueue(queue, payload).await
+    }
+
+    /// Verifies `candidate` against the member's stored `password_hash` in constant time.
+    /// Returns `Ok(false)` for a member with no password set rather than erroring.
+    #[tracing::instrument(skip(self, candidate), fields(member_id = %member_id), err)]
+    pub async fn verify_member_password(&self, member_id: Uuid, candidate: &str) -> Result<bool, SDKError> {
+        let member = get_member_with(self.db_pool.as_ref(), member_id).await?;
+
+        match &member.password_hash {
+            Some(hash) => password::verify_password(candidate, hash),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns `true` if the member's stored hash uses Argon2id parameters that differ from the
+    /// current ones and should be re-hashed (typically right after a successful [`Engine::verify_member_password`]).
+    #[tracing::instrument(skip(self), fields(member_id = %member_id), err)]
+    pub async fn member_password_needs_rehash(&self, member_id: Uuid) -> Result<bool, SDKError> {
+        let member = get_member_with(self.db_pool.as_ref(), member_id).await?;
+
+        Ok(match &member.password_hash {
+            Some(hash) => password::needs_rehash(hash),
+            None => false,
+        })
+    }
+
+    /// Runs `f` against a single open transaction, committing if it returns `Ok` and rolling
+    /// back on any `SDKError`. Use this for multi-entity writes (e.g. creating an organization,
+    /// its owner member, and its default settings) that must succeed or fail together.
+    ///
+    /// CRUD modules expose a `*_with` free function alongside their trait methods (e.g.
+    /// `members::operations::create_member_with`) that accepts any `sqlx::Executor`, so the same
+    /// code path runs against either the pool or the `&mut Transaction` handed to `f` here.
+    pub async fn transaction<F, T>(&self, f: F) -> Result<T, SDKError>
+    where
+        F: for<'c> FnOnce(&'c mut Transaction<'_, Postgres>) -> BoxFuture<'c, Result<T, SDKError>>,
+    {
+        let mut tx = self.db_pool.begin().await?;
+
+        match f(&mut tx).await {
+            Ok(value) => {
+                tx.commit().await?;
+                Ok(value)
+            }
+            Err(err) => {
+                tx.rollback().await?;
+                Err(err)
+            }
+        }
+    }
 }
 
 impl Engine<WithoutContext> {
+    #[tracing::instrument(skip(self, ctx), fields(member_id = %ctx.member_id), err)]
     pub async fn with_context(self, ctx: &EngineContext) -> Result<Engine<WithContext>, SDKError> {
         Ok(Engine {
             _state: PhantomData,
@@ -151,6 +228,7 @@ impl Engine<WithContext> {
 }
 
 impl Engine<WithContext> {
+    #[tracing::instrument(skip(self, value), fields(member_id = %self.state.context.member_id), err)]
     pub async fn initialize_organization(&self, value: CreateOrganizationInput) -> Result<Organization, SDKError> {
         let ctx = self.state.context.clone();
 
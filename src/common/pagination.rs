@@ -0,0 +1,35 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::sdk::SDKError;
+
+/// Opaque keyset cursor encoding the last row seen by a paged list query: the value of its
+/// sort column plus its `id` as a tiebreaker, so pages stay stable under concurrent inserts
+/// (unlike `OFFSET`, which reshuffles as rows are added ahead of the current page).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor<T> {
+    pub sort_value: T,
+    pub id: Uuid,
+}
+
+impl<T> Cursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub fn new(sort_value: T, id: Uuid) -> Self {
+        Self { sort_value, id }
+    }
+
+    /// Encodes this cursor as an opaque, URL-safe token for clients to pass back verbatim.
+    pub fn encode(&self) -> Result<String, SDKError> {
+        let json = serde_json::to_vec(self)?;
+        Ok(URL_SAFE_NO_PAD.encode(json))
+    }
+
+    /// Decodes a token previously produced by [`Cursor::encode`].
+    pub fn decode(token: &str) -> Result<Self, SDKError> {
+        let json = URL_SAFE_NO_PAD.decode(token).map_err(|_| SDKError::InvalidCursor)?;
+        serde_json::from_slice(&json).map_err(|_| SDKError::InvalidCursor)
+    }
+}
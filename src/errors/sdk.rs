@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// Top-level error type returned by every public SDK operation.
+#[derive(Error, Debug)]
+pub enum SDKError {
+    #[error("sdk version not found")]
+    VersionNotFound,
+
+    #[error("invalid pagination cursor")]
+    InvalidCursor,
+
+    #[error("validation error: {0}")]
+    ValidationError(String),
+
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+
+    #[error(transparent)]
+    SqlxMigrateError(#[from] sqlx::migrate::MigrateError),
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
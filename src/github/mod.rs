@@ -0,0 +1,5 @@
+pub mod provider;
+pub mod sync;
+
+pub use provider::{ExternalProfile, GitHubProfileProvider, ProfileProvider};
+pub use sync::{sync_member_from_provider, sync_organization_members_from_github};
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::time::sleep;
+
+use crate::errors::sdk::SDKError;
+
+/// Directory fields a [`ProfileProvider`] can supply for reconciling a [`crate::members::member::Member`].
+#[derive(Debug, Clone)]
+pub struct ExternalProfile {
+    pub external_id: String,
+    pub login: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub email: Option<String>,
+}
+
+/// One page of an organization member directory, plus the cursor to fetch the next one.
+#[derive(Debug, Clone)]
+pub struct ProviderPage {
+    pub profiles: Vec<ExternalProfile>,
+    pub next_cursor: Option<String>,
+}
+
+/// A source of external account profiles, keyed by provider-specific id or login.
+///
+/// `Member` already carries `github_id`; this trait is deliberately provider-agnostic so the same
+/// sync entry points ([`super::sync::sync_member_from_provider`]) can later back `google_id`
+/// without the CRUD layer changing.
+#[async_trait]
+pub trait ProfileProvider: Send + Sync {
+    /// Fetches a single profile by the provider's external id.
+    async fn fetch_profile(&self, external_id: &str) -> Result<ExternalProfile, SDKError>;
+
+    /// Pages through every member of `org`, following `cursor` from a previous call.
+    async fn list_organization_members(&self, org: &str, cursor: Option<String>) -> Result<ProviderPage, SDKError>;
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    id: u64,
+    login: String,
+    name: Option<String>,
+    avatar_url: Option<String>,
+    email: Option<String>,
+}
+
+impl From<GitHubUser> for ExternalProfile {
+    fn from(user: GitHubUser) -> Self {
+        ExternalProfile {
+            external_id: user.id.to_string(),
+            login: user.login,
+            name: user.name,
+            avatar_url: user.avatar_url,
+            email: user.email,
+        }
+    }
+}
+
+/// [`ProfileProvider`] backed by the GitHub REST API.
+pub struct GitHubProfileProvider {
+    client: reqwest::Client,
+    token: String,
+}
+
+impl GitHubProfileProvider {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self { client: reqwest::Client::new(), token: token.into() }
+    }
+
+    fn request(&self, url: &str) -> reqwest::RequestBuilder {
+        self.client
+            .get(url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "plexo-sdk")
+    }
+
+    /// Waits out a rate limit if the response indicates the quota is exhausted, honoring the
+    /// `X-RateLimit-Reset` header GitHub sends instead of guessing a fixed backoff.
+    async fn wait_if_rate_limited(response: &reqwest::Response) -> bool {
+        if response.status() != reqwest::StatusCode::FORBIDDEN && response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return false;
+        }
+
+        let remaining = response
+            .headers()
+            .get("x-ratelimit-remaining")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(1);
+
+        if remaining > 0 {
+            return false;
+        }
+
+        let reset_at = response
+            .headers()
+            .get("x-ratelimit-reset")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<i64>().ok());
+
+        let wait_seconds = match reset_at {
+            Some(reset_at) => (reset_at - chrono::Utc::now().timestamp()).clamp(1, 60),
+            None => 5,
+        };
+
+        sleep(Duration::from_secs(wait_seconds as u64)).await;
+        true
+    }
+}
+
+#[async_trait]
+impl ProfileProvider for GitHubProfileProvider {
+    async fn fetch_profile(&self, external_id: &str) -> Result<ExternalProfile, SDKError> {
+        let url = format!("https://api.github.com/user/{external_id}");
+
+        loop {
+            let response = self
+                .request(&url)
+                .send()
+                .await
+                .map_err(|err| SDKError::ValidationError(format!("github request failed: {err}")))?;
+
+            if GitHubProfileProvider::wait_if_rate_limited(&response).await {
+                continue;
+            }
+
+            let user: GitHubUser = response
+                .error_for_status()
+                .map_err(|err| SDKError::ValidationError(format!("github request failed: {err}")))?
+                .json()
+                .await
+                .map_err(|err| SDKError::ValidationError(format!("invalid github response: {err}")))?;
+
+            return Ok(user.into());
+        }
+    }
+
+    async fn list_organization_members(&self, org: &str, cursor: Option<String>) -> Result<ProviderPage, SDKError> {
+        let page = cursor.as_deref().unwrap_or("1");
+        let url = format!("https://api.github.com/orgs/{org}/members?per_page=100&page={page}");
+
+        loop {
+            let response = self
+                .request(&url)
+                .send()
+                .await
+                .map_err(|err| SDKError::ValidationError(format!("github request failed: {err}")))?;
+
+            if GitHubProfileProvider::wait_if_rate_limited(&response).await {
+                continue;
+            }
+
+            let response = response
+                .error_for_status()
+                .map_err(|err| SDKError::ValidationError(format!("github request failed: {err}")))?;
+
+            let users: Vec<GitHubUser> = response
+                .json()
+                .await
+                .map_err(|err| SDKError::ValidationError(format!("invalid github response: {err}")))?;
+
+            let next_cursor = if users.len() == 100 {
+                Some((page.parse::<u32>().unwrap_or(1) + 1).to_string())
+            } else {
+                None
+            };
+
+            return Ok(ProviderPage { profiles: users.into_iter().map(ExternalProfile::from).collect(), next_cursor });
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use uuid::Uuid;
+
+use crate::{
+    backend::engine::SDKEngine,
+    errors::sdk::SDKError,
+    members::{
+        member::Member,
+        operations::{GetMembersInputBuilder, GetMembersWhereBuilder, MemberCrudOperations, UpdateMemberInputBuilder},
+    },
+};
+
+use super::provider::ProfileProvider;
+
+/// Reconciles a single member's `name`/`photo_url` against `provider`, keyed by the member's
+/// stored `github_id`.
+#[tracing::instrument(skip(engine, provider), fields(member_id = %member_id), err)]
+pub async fn sync_member_from_provider(
+    engine: &SDKEngine,
+    provider: &dyn ProfileProvider,
+    member_id: Uuid,
+) -> Result<Member, SDKError> {
+    let member = engine.get_member(member_id).await?;
+
+    let github_id = member
+        .github_id
+        .ok_or_else(|| SDKError::ValidationError(format!("member {member_id} has no github_id to sync")))?;
+
+    let profile = provider.fetch_profile(&github_id).await?;
+
+    let mut update = UpdateMemberInputBuilder::default();
+    if let Some(name) = profile.name {
+        update = update.name(name);
+    }
+    if let Some(avatar_url) = profile.avatar_url {
+        update = update.photo_url(avatar_url);
+    }
+
+    let update = update.build().map_err(|err| SDKError::ValidationError(err.to_string()))?;
+
+    engine.update_member(member_id, update).await
+}
+
+/// Pages through every member of a GitHub org via `provider` and upserts matching
+/// `Member` rows by `github_id`. Members with no matching `github_id` in Plexo are skipped —
+/// this reconciles existing directory entries, it does not invite new members.
+///
+/// The per-profile `github_id` lookup below goes through `GetMembersWhere::compile()` and
+/// `CompiledFilter::push_onto`, so it depends on those rendering valid, correctly-bound SQL —
+/// verified fixed as of the placeholder-binding fix in `members::filters`/`members::operations`.
+#[tracing::instrument(skip(engine, provider), fields(org = %org), err)]
+pub async fn sync_organization_members_from_github(engine: &SDKEngine, provider: &dyn ProfileProvider, org: &str) -> Result<usize, SDKError> {
+    let mut cursor = None;
+    let mut synced = 0usize;
+
+    loop {
+        let page = provider.list_organization_members(org, cursor.clone()).await?;
+
+        for profile in &page.profiles {
+            let filter = GetMembersWhereBuilder::default()
+                .github_id(profile.external_id.clone())
+                .build()
+                .map_err(|err| SDKError::ValidationError(err.to_string()))?;
+
+            let matches = engine
+                .get_members(
+                    GetMembersInputBuilder::default()
+                        .filter(filter)
+                        .build()
+                        .map_err(|err| SDKError::ValidationError(err.to_string()))?,
+                )
+                .await?;
+
+            let Some(existing) = matches.into_iter().next() else {
+                tracing::debug!(github_id = %profile.external_id, "no matching member, skipping");
+                continue;
+            };
+
+            let mut update = UpdateMemberInputBuilder::default();
+            if let Some(name) = &profile.name {
+                update = update.name(name.clone());
+            }
+            if let Some(avatar_url) = &profile.avatar_url {
+                update = update.photo_url(avatar_url.clone());
+            }
+            let update = update.build().map_err(|err| SDKError::ValidationError(err.to_string()))?;
+
+            engine.update_member(existing.id, update).await?;
+            synced += 1;
+        }
+
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    tracing::info!(synced, org, "github org member sync completed");
+
+    Ok(synced)
+}
@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::errors::sdk::SDKError;
+
+/// Lifecycle of a row in the `job_queue` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "job_status", rename_all = "lowercase")]
+pub enum JobStatus {
+    New,
+    Running,
+    Failed,
+    Done,
+}
+
+/// A claimed unit of work popped off `job_queue`.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: Uuid,
+    pub queue: String,
+    pub payload: Value,
+    pub attempts: i32,
+}
+
+/// Base delay for the exponential backoff applied to failed jobs: `max(attempts, 1)^2 * BASE_BACKOFF`.
+const BASE_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Thin wrapper around the `job_queue` table: enqueueing, claiming, and resolving jobs.
+///
+/// This intentionally does not own a worker loop or handler registry (see [`super::worker::Worker`])
+/// so `Engine` can enqueue jobs without pulling in the polling/dispatch machinery.
+#[derive(Clone)]
+pub struct JobQueue {
+    pool: Pool<Postgres>,
+}
+
+impl JobQueue {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// Inserts a new `new` job, serializing `payload` as JSONB.
+    pub async fn enqueue<P: Serialize>(&self, queue: &str, payload: &P) -> Result<Uuid, SDKError> {
+        self.enqueue_at(queue, payload, Utc::now()).await
+    }
+
+    /// Inserts a new job that should not run before `run_at`.
+    pub async fn enqueue_at<P: Serialize>(&self, queue: &str, payload: &P, run_at: DateTime<Utc>) -> Result<Uuid, SDKError> {
+        let payload = serde_json::to_value(payload)?;
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO job_queue (queue, payload, status, run_at, attempts)
+            VALUES ($1, $2, 'new', $3, 0)
+            RETURNING id
+            "#,
+            queue,
+            payload,
+            run_at,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.id)
+    }
+
+    /// Claims up to `limit` due jobs from `queue`, flipping them to `running` and stamping a
+    /// fresh heartbeat. `FOR UPDATE SKIP LOCKED` lets multiple workers poll concurrently without
+    /// claiming the same row twice.
+    pub async fn claim_next(&self, queue: &str, limit: i64) -> Result<Vec<Job>, SDKError> {
+        let mut tx = self.pool.begin().await?;
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, payload, attempts
+            FROM job_queue
+            WHERE queue = $1 AND status = 'new' AND run_at <= now()
+            ORDER BY run_at
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+            "#,
+            queue,
+            limit,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut jobs = Vec::with_capacity(rows.len());
+        for row in rows {
+            sqlx::query!(
+                r#"UPDATE job_queue SET status = 'running', heartbeat = now() WHERE id = $1"#,
+                row.id,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            jobs.push(Job {
+                id: row.id,
+                queue: queue.to_string(),
+                payload: row.payload,
+                attempts: row.attempts,
+            });
+        }
+
+        tx.commit().await?;
+
+        Ok(jobs)
+    }
+
+    /// Marks a job `done`.
+    pub async fn complete(&self, job_id: Uuid) -> Result<(), SDKError> {
+        sqlx::query!(r#"UPDATE job_queue SET status = 'done' WHERE id = $1"#, job_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Counts a failed attempt and reschedules the job as `new` after an exponential backoff, so the next
+    /// `claim_next` poll picks it back up once `run_at` elapses.
+    pub async fn retry_with_backoff(&self, job_id: Uuid, attempts: i32) -> Result<(), SDKError> {
+        let delay = BASE_BACKOFF * (attempts as u32).max(1).pow(2);
+        let run_at = Utc::now() + chrono::Duration::from_std(delay).unwrap_or_default();
+
+        sqlx::query!(
+            r#"
+            UPDATE job_queue
+            SET status = 'new', attempts = attempts + 1, run_at = $2
+            WHERE id = $1
+            "#,
+            job_id,
+            run_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Permanently marks a job `failed` with no further retries scheduled.
+    pub async fn fail(&self, job_id: Uuid) -> Result<(), SDKError> {
+        sqlx::query!(
+            r#"UPDATE job_queue SET status = 'failed', attempts = attempts + 1 WHERE id = $1"#,
+            job_id,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Resets `running` jobs whose `heartbeat` is older than `timeout` back to `new`, so jobs
+    /// stranded by a crashed worker get picked up again instead of sitting `running` forever.
+    pub async fn reap_stale(&self, timeout: Duration) -> Result<u64, SDKError> {
+        let cutoff = Utc::now() - chrono::Duration::from_std(timeout).unwrap_or_default();
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE job_queue
+            SET status = 'new'
+            WHERE status = 'running' AND heartbeat < $1
+            "#,
+            cutoff,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,128 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::time::{sleep, Instant};
+
+use crate::errors::sdk::SDKError;
+
+use super::queue::JobQueue;
+
+/// Implemented by anything that processes jobs enqueued for a particular queue name.
+///
+/// Handlers receive the raw JSONB payload and deserialize it themselves, matching how
+/// [`JobQueue::enqueue`] accepts any `Serialize` payload without the queue knowing its shape.
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    async fn handle(&self, payload: Value) -> Result<(), SDKError>;
+}
+
+/// Polls `job_queue` and dispatches claimed jobs to registered [`JobHandler`]s.
+///
+/// One `Worker` can serve multiple queues; each is polled independently on the same interval.
+/// This is the out-of-band counterpart to inline LLM calls: enqueue a job instead of awaiting
+/// the `llm_client` call on the request path, and let a `Worker` run it with retries.
+pub struct Worker {
+    queue: JobQueue,
+    handlers: HashMap<String, Arc<dyn JobHandler>>,
+    poll_interval: Duration,
+    max_attempts: i32,
+    reap_interval: Duration,
+    reap_timeout: Duration,
+}
+
+impl Worker {
+    pub fn new(queue: JobQueue) -> Self {
+        Self {
+            queue,
+            handlers: HashMap::new(),
+            poll_interval: Duration::from_secs(1),
+            max_attempts: 5,
+            reap_interval: Duration::from_secs(30),
+            reap_timeout: Duration::from_secs(5 * 60),
+        }
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// How often [`Worker::run`] calls [`Worker::reap`] in the background. Default 30s.
+    pub fn reap_interval(mut self, reap_interval: Duration) -> Self {
+        self.reap_interval = reap_interval;
+        self
+    }
+
+    /// How long a job can sit `running` with no heartbeat before [`Worker::run`]'s periodic
+    /// reap resets it back to `new`. Default 5 minutes.
+    pub fn reap_timeout(mut self, reap_timeout: Duration) -> Self {
+        self.reap_timeout = reap_timeout;
+        self
+    }
+
+    /// Registers `handler` to process jobs enqueued under `queue_name`.
+    pub fn register(mut self, queue_name: impl Into<String>, handler: Arc<dyn JobHandler>) -> Self {
+        self.handlers.insert(queue_name.into(), handler);
+        self
+    }
+
+    /// Runs the poll loop forever. Intended to be spawned onto its own task
+    /// (`tokio::spawn(worker.run())`); it never returns under normal operation.
+    ///
+    /// A transient error polling one queue (a dropped connection, a serialization hiccup) is
+    /// logged and skipped rather than propagated — bubbling it out of the loop would end polling
+    /// for every queue until the process is restarted, defeating the point of a durable queue.
+    ///
+    /// Also calls [`Worker::reap`] on `reap_interval` so jobs stranded `running` by a crashed
+    /// worker get retried automatically — without this, the durability story requires a host app
+    /// to separately discover and schedule `reap` itself.
+    pub async fn run(&self) -> Result<(), SDKError> {
+        let mut last_reap = Instant::now();
+
+        loop {
+            for (queue_name, handler) in &self.handlers {
+                if let Err(err) = self.poll_once(queue_name, handler.as_ref()).await {
+                    tracing::error!(queue = %queue_name, error = %err, "poll_once failed, will retry next interval");
+                }
+            }
+
+            if last_reap.elapsed() >= self.reap_interval {
+                match self.reap(self.reap_timeout).await {
+                    Ok(count) if count > 0 => tracing::info!(count, "reaped stale running jobs"),
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(error = %err, "reap failed, will retry next interval"),
+                }
+                last_reap = Instant::now();
+            }
+
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    async fn poll_once(&self, queue_name: &str, handler: &dyn JobHandler) -> Result<(), SDKError> {
+        let jobs = self.queue.claim_next(queue_name, 10).await?;
+
+        for job in jobs {
+            match handler.handle(job.payload).await {
+                Ok(()) => self.queue.complete(job.id).await?,
+                Err(_) if job.attempts + 1 < self.max_attempts => {
+                    self.queue.retry_with_backoff(job.id, job.attempts).await?
+                }
+                Err(_) => self.queue.fail(job.id).await?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resets jobs left `running` by a crashed worker back to `new` so they get retried.
+    pub async fn reap(&self, timeout: Duration) -> Result<u64, SDKError> {
+        self.queue.reap_stale(timeout).await
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use sqlx::{Postgres, QueryBuilder};
+
+/// Internal marker `compile()` writes in place of a real `$n` placeholder. `QueryBuilder` assigns
+/// the actual positional number when [`CompiledFilter::push_onto`] pushes each bind, so this never
+/// reaches a real query — it only has to be a token that can't appear in a column name, operator,
+/// or `_and`/`_or` nesting (parens/spaces) that `compile()` emits around it.
+pub(crate) const PLACEHOLDER_MARKER: &str = "\u{0}";
+
+/// A single bound value used by a compiled [`super::operations::GetMembersWhere`] fragment.
+///
+/// `sqlx::QueryBuilder` needs concrete, encodable types to push as bind parameters, so filter
+/// values are normalized into this enum rather than kept as arbitrary strings.
+#[derive(Debug, Clone)]
+pub enum FilterValue {
+    Text(String),
+    Role(String),
+    Timestamp(chrono::DateTime<chrono::Utc>),
+}
+
+/// Comparison operator for a single field predicate.
+///
+/// `Eq` keeps the previous exact-match behavior; the rest exist so callers building
+/// analytics/issue-style filters can express contains and range queries without dropping
+/// down to raw SQL.
+///
+/// There is deliberately no `In`/`ANY` variant yet: a correct one needs a list-valued
+/// [`FilterValue`] and `TextFilter`/`TimestampFilter` support for it, which no caller needs
+/// today. Add it together with that plumbing when a real `IN (...)` use case shows up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOperator {
+    Eq,
+    Like,
+    ILike,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl Display for FilterOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            FilterOperator::Eq => "=",
+            FilterOperator::Like => "LIKE",
+            FilterOperator::ILike => "ILIKE",
+            FilterOperator::GreaterThan => ">",
+            FilterOperator::GreaterThanOrEqual => ">=",
+            FilterOperator::LessThan => "<",
+            FilterOperator::LessThanOrEqual => "<=",
+        };
+        f.write_str(op)
+    }
+}
+
+/// A single `column <op> $n` predicate plus the value it binds.
+#[derive(Debug, Clone)]
+pub struct FieldFilter {
+    pub column: &'static str,
+    pub operator: FilterOperator,
+    pub value: FilterValue,
+}
+
+impl FieldFilter {
+    pub fn new(column: &'static str, operator: FilterOperator, value: FilterValue) -> Self {
+        Self { column, operator, value }
+    }
+}
+
+/// Output of [`super::operations::GetMembersWhere::compile`]: a SQL fragment with
+/// [`PLACEHOLDER_MARKER`] standing in for each bind, and the ordered values those markers
+/// correspond to. Use [`CompiledFilter::push_onto`] to splice this into a live `QueryBuilder` —
+/// never interpolate `sql` directly, since its markers are not valid SQL on their own.
+#[derive(Debug, Clone, Default)]
+pub struct CompiledFilter {
+    pub sql: String,
+    pub values: Vec<FilterValue>,
+}
+
+impl CompiledFilter {
+    /// Pushes this fragment onto `builder`, replacing each [`PLACEHOLDER_MARKER`] with a real
+    /// bind via `builder.push_bind`, so `QueryBuilder` assigns and owns every placeholder number —
+    /// the fragment itself never writes a `$n` that could collide with other binds on `builder`.
+    pub fn push_onto(&self, builder: &mut QueryBuilder<Postgres>) {
+        let mut parts = self.sql.split(PLACEHOLDER_MARKER);
+
+        if let Some(first) = parts.next() {
+            builder.push(first);
+        }
+
+        for (value, part) in self.values.iter().zip(parts) {
+            match value {
+                FilterValue::Text(value) => builder.push_bind(value.clone()),
+                FilterValue::Role(value) => builder.push_bind(value.clone()),
+                FilterValue::Timestamp(value) => builder.push_bind(*value),
+            };
+            builder.push(part);
+        }
+    }
+}
+
+/// Counts placeholders emitted across a single filter compilation, including recursive
+/// `_and`/`_or` groups. `compile()` no longer renders this into the SQL text (see
+/// [`PLACEHOLDER_MARKER`]), but still threads it through nested groups so a caller can tell how
+/// many binds a compiled filter will consume.
+pub struct PlaceholderCounter {
+    next: usize,
+}
+
+impl PlaceholderCounter {
+    pub fn starting_at(next: usize) -> Self {
+        Self { next }
+    }
+
+    pub fn next(&mut self) -> usize {
+        let current = self.next;
+        self.next += 1;
+        current
+    }
+}
+
+impl Default for PlaceholderCounter {
+    fn default() -> Self {
+        Self::starting_at(1)
+    }
+}
+
+/// A text-valued predicate with an explicit operator, so callers can ask for `LIKE`/`ILIKE`
+/// matches instead of only exact equality. Plain string setters (`"foo".into()`) still compile
+/// to an `Eq` predicate, matching the previous exact-match-only behavior.
+#[derive(Debug, Clone)]
+pub struct TextFilter {
+    pub value: String,
+    pub operator: FilterOperator,
+}
+
+impl TextFilter {
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self { value: value.into(), operator: FilterOperator::Eq }
+    }
+
+    /// Contains-match: wraps `value` in `%...%` so it matches anywhere in the column, not just
+    /// an exact equal string.
+    pub fn like(value: impl Into<String>) -> Self {
+        Self { value: format!("%{}%", value.into()), operator: FilterOperator::Like }
+    }
+
+    /// Case-insensitive contains-match; see [`TextFilter::like`].
+    pub fn ilike(value: impl Into<String>) -> Self {
+        Self { value: format!("%{}%", value.into()), operator: FilterOperator::ILike }
+    }
+}
+
+impl From<String> for TextFilter {
+    fn from(value: String) -> Self {
+        TextFilter::eq(value)
+    }
+}
+
+impl From<&str> for TextFilter {
+    fn from(value: &str) -> Self {
+        TextFilter::eq(value)
+    }
+}
+
+/// A `chrono::DateTime<Utc>`-valued predicate for range queries (e.g. "members created after X").
+#[derive(Debug, Clone)]
+pub struct TimestampFilter {
+    pub value: chrono::DateTime<chrono::Utc>,
+    pub operator: FilterOperator,
+}
+
+impl TimestampFilter {
+    pub fn new(value: chrono::DateTime<chrono::Utc>, operator: FilterOperator) -> Self {
+        Self { value, operator }
+    }
+}
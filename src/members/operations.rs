@@ -3,22 +3,50 @@ use std::str::FromStr;
 use async_trait::async_trait;
 
 use derive_builder::Builder;
-use sqlx::{Row};
+use futures::Stream;
+use sqlx::{postgres::Postgres, QueryBuilder, Row};
+use tracing::Instrument;
 use uuid::Uuid;
 
-use crate::{backend::engine::SDKEngine, common::commons::SortOrder, errors::sdk::SDKError};
-
-use super::member::{Member, MemberRole};
+use crate::{
+    backend::engine::SDKEngine,
+    common::{commons::SortOrder, pagination::Cursor},
+    errors::sdk::SDKError,
+};
+
+use super::{
+    filters::{CompiledFilter, FilterOperator, FilterValue, PlaceholderCounter, TextFilter, TimestampFilter, PLACEHOLDER_MARKER},
+    member::{Member, MemberRole},
+    password::hash_password,
+};
+
+/// A single page of members plus the cursor needed to fetch the next one. `has_more` is `true`
+/// only when rows exist beyond this page, which one extra fetched row is used to detect.
+pub struct MembersPage {
+    pub members: Vec<Member>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
 
 #[async_trait]
 pub trait MemberCrudOperations {
     async fn create_member(&self, input: CreateMemberInput) -> Result<Member, SDKError>;
     async fn get_member(&self, id: Uuid) -> Result<Member, SDKError>;
     async fn get_members(&self, input: GetMembersInput) -> Result<Vec<Member>, SDKError>;
+    /// Keyset-paginated variant of [`MemberCrudOperations::get_members`]: `input.cursor`, if set,
+    /// resumes after the last row of a previous page instead of paying for an `OFFSET` scan.
+    async fn get_members_page(&self, input: GetMembersInput) -> Result<MembersPage, SDKError>;
+    /// Walks every page of `input` as an async stream, so callers can iterate an entire org's
+    /// members without manually tracking cursors.
+    fn stream_members(&self, input: GetMembersInput) -> BoxStream<'_, Result<Member, SDKError>>;
     async fn update_member(&self, id: Uuid, input: UpdateMemberInput) -> Result<Member, SDKError>;
     async fn delete_member(&self, id: Uuid) -> Result<Member, SDKError>;
 }
 
+/// Re-exported so implementors of [`MemberCrudOperations::stream_members`] outside this crate
+/// don't need to depend on `futures` directly just to name the return type.
+pub type BoxStream<'a, T> = std::pin::Pin<Box<dyn Stream<Item = T> + Send + 'a>>;
+
 #[derive(Builder)]
 #[builder(pattern = "owned")]
 pub struct CreateMemberInput {
@@ -28,7 +56,11 @@ pub struct CreateMemberInput {
     pub github_id: Option<String>,
     pub google_id: Option<String>,
     pub photo_url: Option<String>,
-    pub password_hash: Option<String>,
+    /// Plaintext password, if this member authenticates with one. Hashed with Argon2id inside
+    /// `create_member`/`create_member_with` before it ever reaches SQL or a log line — callers
+    /// must never pass a pre-hashed value here.
+    #[builder(setter(strip_option), default)]
+    pub password: Option<String>,
 }
 
 #[derive(Builder)]
@@ -46,11 +78,13 @@ pub struct UpdateMemberInput {
     pub google_id: Option<String>,
     #[builder(setter(strip_option), default)]
     pub photo_url: Option<String>,
+    /// Plaintext password to set. Hashed with Argon2id inside `update_member`/`update_member_with`;
+    /// never exposed as `password_hash` on this input.
     #[builder(setter(strip_option), default)]
-    pub password_hash: Option<String>,
+    pub password: Option<String>,
 }
 
-#[derive(Builder)]
+#[derive(Builder, Clone)]
 #[builder(pattern = "owned")]
 pub struct GetMembersInput {
     #[builder(setter(strip_option), default)]
@@ -65,23 +99,30 @@ pub struct GetMembersInput {
     pub limit: Option<i32>,
     #[builder(setter(into, strip_option), default = "Some(0)")]
     pub offset: Option<i32>,
+
+    /// Opaque cursor from a previous [`MembersPage::next_cursor`], used by keyset-paginated calls
+    /// ([`MemberCrudOperations::get_members_page`]), which never read `offset`.
+    #[builder(setter(strip_option, into), default)]
+    pub cursor: Option<String>,
 }
 
-#[derive(Builder)]
+#[derive(Builder, Clone)]
 #[builder(pattern = "owned")]
 pub struct GetMembersWhere {
-    #[builder(setter(strip_option), default)]
-    pub name: Option<String>,
-    #[builder(setter(strip_option), default)]
-    pub email: Option<String>,
+    #[builder(setter(strip_option, into), default)]
+    pub name: Option<TextFilter>,
+    #[builder(setter(strip_option, into), default)]
+    pub email: Option<TextFilter>,
     #[builder(setter(strip_option), default)]
     pub role: Option<MemberRole>,
+    #[builder(setter(strip_option, into), default)]
+    pub github_id: Option<TextFilter>,
+    #[builder(setter(strip_option, into), default)]
+    pub google_id: Option<TextFilter>,
+    #[builder(setter(strip_option, into), default)]
+    pub photo_url: Option<TextFilter>,
     #[builder(setter(strip_option), default)]
-    pub github_id: Option<String>,
-    #[builder(setter(strip_option), default)]
-    pub google_id: Option<String>,
-    #[builder(setter(strip_option), default)]
-    pub photo_url: Option<String>,
+    pub created_at: Option<TimestampFilter>,
 
     #[builder(setter(strip_option), default)]
     pub _and: Option<Vec<GetMembersWhere>>,
@@ -90,247 +131,510 @@ pub struct GetMembersWhere {
 }
 
 impl GetMembersWhere {
-    pub fn compile_sql(&self) -> String {
-        let mut where_clause = String::new();
+    /// Compiles this filter tree into a parameterized SQL fragment plus its ordered bind values.
+    ///
+    /// `counter` is shared across the whole recursion so nested `_and`/`_or` groups keep
+    /// incrementing the placeholder index rather than each restarting at `$1`.
+    pub fn compile(&self, counter: &mut PlaceholderCounter) -> CompiledFilter {
         let mut and_clauses = Vec::new();
         let mut or_clauses = Vec::new();
+        let mut values = Vec::new();
+
+        let mut push_field = |column: &'static str, operator: FilterOperator, value: FilterValue, clauses: &mut Vec<String>, values: &mut Vec<FilterValue>| {
+            counter.next();
+            clauses.push(format!("{column} {operator} {PLACEHOLDER_MARKER}"));
+            values.push(value);
+        };
 
         if let Some(name) = &self.name {
-            and_clauses.push(format!("name = '{}'", name));
+            push_field("name", name.operator, FilterValue::Text(name.value.clone()), &mut and_clauses, &mut values);
         }
         if let Some(email) = &self.email {
-            and_clauses.push(format!("email = '{}'", email));
+            push_field("email", email.operator, FilterValue::Text(email.value.clone()), &mut and_clauses, &mut values);
         }
         if let Some(role) = &self.role {
-            and_clauses.push(format!("role = '{}'", role));
+            push_field("role", FilterOperator::Eq, FilterValue::Role(role.to_string()), &mut and_clauses, &mut values);
         }
         if let Some(github_id) = &self.github_id {
-            and_clauses.push(format!("github_id = '{}'", github_id));
+            push_field("github_id", github_id.operator, FilterValue::Text(github_id.value.clone()), &mut and_clauses, &mut values);
         }
         if let Some(google_id) = &self.google_id {
-            and_clauses.push(format!("google_id = '{}'", google_id));
+            push_field("google_id", google_id.operator, FilterValue::Text(google_id.value.clone()), &mut and_clauses, &mut values);
         }
         if let Some(photo_url) = &self.photo_url {
-            and_clauses.push(format!("photo_url = '{}'", photo_url));
+            push_field("photo_url", photo_url.operator, FilterValue::Text(photo_url.value.clone()), &mut and_clauses, &mut values);
+        }
+        if let Some(created_at) = &self.created_at {
+            push_field("created_at", created_at.operator, FilterValue::Timestamp(created_at.value), &mut and_clauses, &mut values);
         }
 
+        drop(push_field);
+
         if let Some(and) = &self._and {
             for and_clause in and {
-                and_clauses.push(and_clause.compile_sql());
+                let compiled = and_clause.compile(counter);
+                if !compiled.sql.is_empty() {
+                    and_clauses.push(compiled.sql);
+                    values.extend(compiled.values);
+                }
             }
         }
         if let Some(or) = &self._or {
             for or_clause in or {
-                or_clauses.push(or_clause.compile_sql());
+                let compiled = or_clause.compile(counter);
+                if !compiled.sql.is_empty() {
+                    or_clauses.push(compiled.sql);
+                    values.extend(compiled.values);
+                }
             }
         }
 
+        let mut sql = String::new();
         if !and_clauses.is_empty() {
-            where_clause.push_str(&format!("({})", and_clauses.join(" AND ")));
+            sql.push_str(&format!("({})", and_clauses.join(" AND ")));
         }
         if !or_clauses.is_empty() {
-            if !where_clause.is_empty() {
-                where_clause.push_str(" OR ");
+            if !sql.is_empty() {
+                sql.push_str(" OR ");
             }
-            where_clause.push_str(&format!("({})", or_clauses.join(" OR ")));
+            sql.push_str(&format!("({})", or_clauses.join(" OR ")));
         }
 
-        where_clause
+        CompiledFilter { sql, values }
     }
 }
 
-#[async_trait]
-impl MemberCrudOperations for SDKEngine {
-    async fn create_member(&self, input: CreateMemberInput) -> Result<Member, SDKError> {
-        let member_final_info = sqlx::query!(
-            r#"
-            INSERT INTO members (name, email, role, github_id, google_id, photo_url, password_hash)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING *
-            "#,
-            input.name,
-            input.email,
-            input.role.to_string(),
-            input.github_id,
-            input.google_id,
-            input.photo_url,
-            input.password_hash
-        )
-        .fetch_one(self.pool.as_ref())
-        .await?;
-
-        let member = Member {
-            id: member_final_info.id,
-            created_at: member_final_info.created_at,
-            updated_at: member_final_info.updated_at,
-            name: member_final_info.name,
-            email: member_final_info.email,
-            role: member_final_info
-                .role
-                .and_then(|a| MemberRole::from_str(&a).ok())
-                .unwrap_or_default(),
-            github_id: member_final_info.github_id,
-            google_id: member_final_info.google_id,
-            photo_url: member_final_info.photo_url,
-            password_hash: member_final_info.password_hash,
-        };
+/// Inserts a new member through `executor`, which may be a pool (autocommit) or an open
+/// [`sqlx::Transaction`] (see [`crate::backend::v2::Engine::transaction`]) so callers can group
+/// member creation with other writes atomically.
+pub async fn create_member_with<'c, E>(executor: E, input: CreateMemberInput) -> Result<Member, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let password_hash = input.password.as_deref().map(hash_password).transpose()?;
+
+    let member_final_info = sqlx::query!(
+        r#"
+        INSERT INTO members (name, email, role, github_id, google_id, photo_url, password_hash)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING *
+        "#,
+        input.name,
+        input.email,
+        input.role.to_string(),
+        input.github_id,
+        input.google_id,
+        input.photo_url,
+        password_hash
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(Member {
+        id: member_final_info.id,
+        created_at: member_final_info.created_at,
+        updated_at: member_final_info.updated_at,
+        name: member_final_info.name,
+        email: member_final_info.email,
+        role: member_final_info
+            .role
+            .and_then(|a| MemberRole::from_str(&a).ok())
+            .unwrap_or_default(),
+        github_id: member_final_info.github_id,
+        google_id: member_final_info.google_id,
+        photo_url: member_final_info.photo_url,
+        password_hash: member_final_info.password_hash,
+    })
+}
 
-        Ok(member)
+/// Reads a single member through `executor` (pool or open transaction).
+pub async fn get_member_with<'c, E>(executor: E, id: Uuid) -> Result<Member, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let member_info = sqlx::query!(
+        r#"
+        SELECT id, created_at, updated_at, name, email, role, github_id, google_id, photo_url, password_hash
+        FROM members
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(Member {
+        id: member_info.id,
+        created_at: member_info.created_at,
+        updated_at: member_info.updated_at,
+        name: member_info.name,
+        email: member_info.email,
+        role: member_info
+            .role
+            .and_then(|a| MemberRole::from_str(&a).ok())
+            .unwrap_or_default(),
+        github_id: member_info.github_id,
+        google_id: member_info.google_id,
+        photo_url: member_info.photo_url,
+        password_hash: member_info.password_hash,
+    })
+}
+
+/// Lists members through `executor` (pool or open transaction).
+pub async fn get_members_with<'c, E>(executor: E, input: GetMembersInput) -> Result<Vec<Member>, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT * FROM members ");
+
+    if let Some(filter) = &input.filter {
+        let mut counter = PlaceholderCounter::default();
+        let compiled = filter.compile(&mut counter);
+
+        if !compiled.sql.is_empty() {
+            builder.push("WHERE ");
+            compiled.push_onto(&mut builder);
+            builder.push(" ");
+        }
     }
 
-    async fn get_member(&self, id: Uuid) -> Result<Member, SDKError> {
-        let member_info = sqlx::query!(
-            r#"
-            SELECT id, created_at, updated_at, name, email, role, github_id, google_id, photo_url, password_hash
-            FROM members
-            WHERE id = $1
-            "#,
-            id
-        )
-        .fetch_one(self.pool.as_ref())
-        .await?;
-
-        let member = Member {
-            id: member_info.id,
-            created_at: member_info.created_at,
-            updated_at: member_info.updated_at,
-            name: member_info.name,
-            email: member_info.email,
-            role: member_info
-                .role
-                .and_then(|a| MemberRole::from_str(&a).ok())
-                .unwrap_or_default(),
-            github_id: member_info.github_id,
-            google_id: member_info.google_id,
-            photo_url: member_info.photo_url,
-            password_hash: member_info.password_hash,
-        };
+    // sort_by is a column name, not a bound value, since QueryBuilder can't parameterize
+    // identifiers; callers must not pass untrusted input here.
+    if let Some(sort_by) = &input.sort_by {
+        builder.push(format!("ORDER BY {sort_by} "));
 
-        Ok(member)
+        if let Some(sort_order) = &input.sort_order {
+            builder.push(format!("{sort_order} "));
+        }
     }
 
-    async fn get_members(&self, input: GetMembersInput) -> Result<Vec<Member>, SDKError> {
-        let mut query = "SELECT * FROM members ".to_string();
+    if let Some(limit) = input.limit {
+        builder.push("LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" ");
+    }
 
-        if let Some(filter) = input.filter {
-            query.push_str(format!("WHERE {} ", filter.compile_sql()).as_str());
-        }
+    if let Some(offset) = input.offset {
+        builder.push("OFFSET ");
+        builder.push_bind(offset);
+        builder.push(" ");
+    }
 
-        if let Some(sort_by) = input.sort_by {
-            query.push_str(format!("ORDER BY {} ", sort_by).as_str());
-        }
+    let query = builder.build();
+    tracing::debug!(sql = query.sql(), "compiled get_members query");
+    let members_info = query.fetch_all(executor).await?;
 
-        if let Some(sort_order) = input.sort_order {
-            query.push_str(format!("{} ", sort_order).as_str());
-        }
+    members_info.iter().map(member_from_row).collect::<Result<Vec<Member>, SDKError>>()
+}
 
-        if let Some(limit) = input.limit {
-            query.push_str(format!("LIMIT {} ", limit).as_str());
+/// Keyset-paginated listing through `executor` (pool or open transaction).
+pub async fn get_members_page_with<'c, E>(executor: E, input: GetMembersInput) -> Result<MembersPage, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let sort_by = input.sort_by.clone().unwrap_or_else(|| "id".to_string());
+    let limit = input.limit.unwrap_or(100);
+
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT * FROM members ");
+    let mut counter = PlaceholderCounter::default();
+    let mut has_where = false;
+
+    if let Some(filter) = &input.filter {
+        let compiled = filter.compile(&mut counter);
+        if !compiled.sql.is_empty() {
+            builder.push("WHERE (");
+            compiled.push_onto(&mut builder);
+            builder.push(") ");
+            has_where = true;
         }
+    }
 
-        if let Some(offset) = input.offset {
-            query.push_str(format!("OFFSET {} ", offset).as_str());
-        }
+    if let Some(cursor) = &input.cursor {
+        let cursor: Cursor<String> = Cursor::decode(cursor)?;
 
-        let members_info = sqlx::query(query.as_str())
-            .fetch_all(self.pool.as_ref())
-            .await?;
-
-        let members = members_info
-            .iter()
-            .map(|x| Member {
-                id: x.get("id"),
-                created_at: x.get("created_at"),
-                updated_at: x.get("updated_at"),
-                name: x.get("name"),
-                email: x.get("email"),
-                role: x
-                    .get::<'_, Option<String>, _>("status")
-                    .and_then(|a| MemberRole::from_str(&a).ok())
-                    .unwrap_or_default(),
-                github_id: x.get("github_id"),
-                google_id: x.get("google_id"),
-                photo_url: x.get("photo_url"),
-                password_hash: x.get("password_hash"),
-            })
-            .collect::<Vec<Member>>();
+        builder.push(if has_where { "AND " } else { "WHERE " });
 
+        // Cast to text so this keyset comparison stays generic across sort columns; this
+        // preserves correct ordering for text/uuid columns and for ISO-8601 timestamps, which
+        // is what member sort columns are today.
+        //
+        // The comparator direction must follow sort_order: a descending list's next page is
+        // everything that sorts *before* the last-seen row, not after.
+        let comparator = match input.sort_order {
+            Some(SortOrder::Desc) => "<",
+            _ => ">",
+        };
+        builder.push(format!("({sort_by}::text, id) {comparator} ("));
+        builder.push_bind(cursor.sort_value);
+        builder.push(", ");
+        builder.push_bind(cursor.id);
+        builder.push(") ");
+    }
+    // Both keys take the same direction so the row-value comparison above matches this order.
+    match &input.sort_order {
+        Some(sort_order) => builder.push(format!("ORDER BY {sort_by} {sort_order}, id {sort_order} ")),
+        None => builder.push(format!("ORDER BY {sort_by}, id ")),
+    };
+
+    // Fetch one extra row so has_more can be determined without a second round-trip.
+    builder.push("LIMIT ");
+    builder.push_bind(limit + 1);
+
+    let query = builder.build();
+    tracing::debug!(sql = query.sql(), "compiled get_members_page query");
+    let rows = query.fetch_all(executor).await?;
+
+    let has_more = rows.len() as i32 > limit;
+    let mut members = rows
+        .iter()
+        .map(member_from_row)
+        .take(limit as usize)
+        .collect::<Result<Vec<Member>, SDKError>>()?;
+
+    let next_cursor = if has_more {
+        members.last().map(|last| Cursor::new(sort_by_value(last, &sort_by), last.id).encode()).transpose()?
+    } else {
+        None
+    };
+
+    if members.len() as i32 > limit {
+        members.truncate(limit as usize);
+    }
+
+    Ok(MembersPage { members, next_cursor, has_more })
+}
+
+#[async_trait]
+impl MemberCrudOperations for SDKEngine {
+    #[tracing::instrument(name = "create_member", skip(self, input), fields(email = %input.email), err)]
+    async fn create_member(&self, input: CreateMemberInput) -> Result<Member, SDKError> {
+        create_member_with(self.pool.as_ref(), input).await
+    }
+
+    #[tracing::instrument(name = "get_member", skip(self), fields(member_id = %id), err)]
+    async fn get_member(&self, id: Uuid) -> Result<Member, SDKError> {
+        get_member_with(self.pool.as_ref(), id).await
+    }
+
+    #[tracing::instrument(name = "get_members", skip(self, input), err)]
+    async fn get_members(&self, input: GetMembersInput) -> Result<Vec<Member>, SDKError> {
+        let members = get_members_with(self.pool.as_ref(), input).await?;
+        tracing::debug!(row_count = members.len(), "get_members completed");
         Ok(members)
     }
 
-    async fn update_member(&self, id: Uuid, input: UpdateMemberInput) -> Result<Member, SDKError> {
-        let member_final_info = sqlx::query!(
-            r#"
-            UPDATE members
-            SET
-                name = COALESCE($1, name),
-                email = COALESCE($2, email),
-                role = COALESCE($3, role),
-                github_id = COALESCE($4, github_id),
-                google_id = COALESCE($5, google_id),
-                photo_url = COALESCE($6, photo_url),
-                password_hash = COALESCE($7, password_hash)
-            WHERE id = $8
-            RETURNING *
-            "#,
-            input.name,
-            input.email,
-            input.role.map(|role| role.to_string()),
-            input.github_id,
-            input.google_id,
-            input.photo_url,
-            input.password_hash,
-            id
+    #[tracing::instrument(name = "get_members_page", skip(self, input), err)]
+    async fn get_members_page(&self, input: GetMembersInput) -> Result<MembersPage, SDKError> {
+        let page = get_members_page_with(self.pool.as_ref(), input).await?;
+        tracing::debug!(row_count = page.members.len(), has_more = page.has_more, "get_members_page completed");
+        Ok(page)
+    }
+
+    #[tracing::instrument(name = "stream_members", skip(self, input))]
+    fn stream_members(&self, input: GetMembersInput) -> BoxStream<'_, Result<Member, SDKError>> {
+        let span = tracing::Span::current();
+
+        Box::pin(
+            async_stream::try_stream! {
+                let mut next_input = Some(input);
+                let mut page_no = 0u32;
+
+                while let Some(current) = next_input.take() {
+                    let template = current.clone();
+                    let page = self.get_members_page(current).await?;
+                    page_no += 1;
+                    tracing::debug!(page = page_no, row_count = page.members.len(), has_more = page.has_more, "stream_members page fetched");
+
+                    for member in page.members {
+                        yield member;
+                    }
+
+                    if page.has_more {
+                        let mut next = template;
+                        next.cursor = page.next_cursor;
+                        next_input = Some(next);
+                    }
+                }
+            }
+            .instrument(span),
         )
-        .fetch_one(self.pool.as_ref())
-        .await?;
-
-        let member = Member {
-            id: member_final_info.id,
-            created_at: member_final_info.created_at,
-            updated_at: member_final_info.updated_at,
-            name: member_final_info.name,
-            email: member_final_info.email,
-            role: member_final_info
-                .role
-                .and_then(|a| MemberRole::from_str(&a).ok())
-                .unwrap_or_default(),
-            github_id: member_final_info.github_id,
-            google_id: member_final_info.google_id,
-            photo_url: member_final_info.photo_url,
-            password_hash: member_final_info.password_hash,
-        };
+    }
 
-        Ok(member)
+    #[tracing::instrument(name = "update_member", skip(self, input), fields(member_id = %id), err)]
+    async fn update_member(&self, id: Uuid, input: UpdateMemberInput) -> Result<Member, SDKError> {
+        update_member_with(self.pool.as_ref(), id, input).await
     }
 
+    #[tracing::instrument(name = "delete_member", skip(self), fields(member_id = %id), err)]
     async fn delete_member(&self, id: Uuid) -> Result<Member, SDKError> {
-        let member_info = sqlx::query!(
-            r#"
-            DELETE FROM members WHERE id = $1
-            RETURNING *
-            "#,
-            id
-        )
-        .fetch_one(self.pool.as_ref())
-        .await?;
-
-        let member = Member {
-            id: member_info.id,
-            created_at: member_info.created_at,
-            updated_at: member_info.updated_at,
-            name: member_info.name,
-            email: member_info.email,
-            role: member_info
-                .role
-                .and_then(|a| MemberRole::from_str(&a).ok())
-                .unwrap_or_default(),
-            github_id: member_info.github_id,
-            google_id: member_info.google_id,
-            photo_url: member_info.photo_url,
-            password_hash: member_info.password_hash,
-        };
+        delete_member_with(self.pool.as_ref(), id).await
+    }
+}
+
+/// Updates a member through `executor` (pool or open transaction).
+pub async fn update_member_with<'c, E>(executor: E, id: Uuid, input: UpdateMemberInput) -> Result<Member, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let password_hash = input.password.as_deref().map(hash_password).transpose()?;
+
+    let member_final_info = sqlx::query!(
+        r#"
+        UPDATE members
+        SET
+            name = COALESCE($1, name),
+            email = COALESCE($2, email),
+            role = COALESCE($3, role),
+            github_id = COALESCE($4, github_id),
+            google_id = COALESCE($5, google_id),
+            photo_url = COALESCE($6, photo_url),
+            password_hash = COALESCE($7, password_hash)
+        WHERE id = $8
+        RETURNING *
+        "#,
+        input.name,
+        input.email,
+        input.role.map(|role| role.to_string()),
+        input.github_id,
+        input.google_id,
+        input.photo_url,
+        password_hash,
+        id
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(Member {
+        id: member_final_info.id,
+        created_at: member_final_info.created_at,
+        updated_at: member_final_info.updated_at,
+        name: member_final_info.name,
+        email: member_final_info.email,
+        role: member_final_info
+            .role
+            .and_then(|a| MemberRole::from_str(&a).ok())
+            .unwrap_or_default(),
+        github_id: member_final_info.github_id,
+        google_id: member_final_info.google_id,
+        photo_url: member_final_info.photo_url,
+        password_hash: member_final_info.password_hash,
+    })
+}
+
+/// Deletes a member through `executor` (pool or open transaction).
+pub async fn delete_member_with<'c, E>(executor: E, id: Uuid) -> Result<Member, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let member_info = sqlx::query!(
+        r#"
+        DELETE FROM members WHERE id = $1
+        RETURNING *
+        "#,
+        id
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(Member {
+        id: member_info.id,
+        created_at: member_info.created_at,
+        updated_at: member_info.updated_at,
+        name: member_info.name,
+        email: member_info.email,
+        role: member_info
+            .role
+            .and_then(|a| MemberRole::from_str(&a).ok())
+            .unwrap_or_default(),
+        github_id: member_info.github_id,
+        google_id: member_info.google_id,
+        photo_url: member_info.photo_url,
+        password_hash: member_info.password_hash,
+    })
+}
+
+/// Builds a `Member` from a `get_members`/`get_members_page` row, always scrubbing
+/// `password_hash` — listing projections must never surface it, hashed or not.
+fn member_from_row(row: &sqlx::postgres::PgRow) -> Result<Member, SDKError> {
+    Ok(Member {
+        id: row.try_get("id")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        name: row.try_get("name")?,
+        email: row.try_get("email")?,
+        role: row
+            .try_get::<'_, Option<String>, _>("role")?
+            .and_then(|a| MemberRole::from_str(&a).ok())
+            .unwrap_or_default(),
+        github_id: row.try_get("github_id")?,
+        google_id: row.try_get("google_id")?,
+        photo_url: row.try_get("photo_url")?,
+        password_hash: None,
+    })
+}
+
+/// Renders a member's value for `column` as text, for embedding in a keyset [`Cursor`].
+fn sort_by_value(member: &Member, column: &str) -> String {
+    match column {
+        "name" => member.name.clone(),
+        "email" => member.email.clone(),
+        "role" => member.role.to_string(),
+        "created_at" => member.created_at.to_rfc3339(),
+        "updated_at" => member.updated_at.to_rfc3339(),
+        _ => member.id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression test for the bug where `compile()`'s pre-rendered `$n` text and
+    /// `QueryBuilder::push_bind`'s own numbering both claimed the same placeholders, leaving an
+    /// orphaned `$n` after every predicate. `push_onto` must leave exactly one real `$n` per bound
+    /// value and no leftover [`PLACEHOLDER_MARKER`]s.
+    #[test]
+    fn push_onto_binds_each_value_exactly_once_with_no_orphaned_markers() {
+        let filter = GetMembersWhereBuilder::default()
+            .name(TextFilter::like("foo"))
+            .email(TextFilter::eq("a@b.com"))
+            .build()
+            .unwrap();
+
+        let mut counter = PlaceholderCounter::default();
+        let compiled = filter.compile(&mut counter);
+        assert_eq!(compiled.values.len(), 2);
+
+        let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT * FROM members WHERE ");
+        compiled.push_onto(&mut builder);
+
+        let rendered = builder.sql();
+        assert_eq!(rendered.matches('$').count(), 2, "expected exactly one placeholder per bound value: {rendered}");
+        assert!(!rendered.contains(PLACEHOLDER_MARKER), "no unresolved placeholder markers should remain: {rendered}");
+    }
+
+    /// Nested `_and` groups must share the same placeholder count as their parent so a compiled
+    /// filter can still be spliced into one query with a single consistent set of binds.
+    #[test]
+    fn compile_threads_placeholder_count_through_nested_and_groups() {
+        let nested = GetMembersWhereBuilder::default().github_id(TextFilter::eq("42")).build().unwrap();
+        let filter = GetMembersWhereBuilder::default().name(TextFilter::eq("foo"))._and(vec![nested]).build().unwrap();
+
+        let mut counter = PlaceholderCounter::default();
+        let compiled = filter.compile(&mut counter);
+
+        assert_eq!(compiled.values.len(), 2);
+        assert_eq!(compiled.sql.matches(PLACEHOLDER_MARKER).count(), 2);
+        // One outer and one nested predicate consumed $1 and $2 from the shared counter.
+        assert_eq!(counter.next(), 3);
+    }
 
-        Ok(member)
+    /// `TextFilter::like`/`ilike` must wrap the value for a contains-match — see the chunk0-1
+    /// review fix — not bind it verbatim as an exact-equality string.
+    #[test]
+    fn like_and_ilike_wrap_value_in_wildcards() {
+        assert_eq!(TextFilter::like("foo").value, "%foo%");
+        assert_eq!(TextFilter::ilike("foo").value, "%foo%");
+        assert_eq!(TextFilter::eq("foo").value, "foo");
     }
 }
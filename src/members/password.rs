@@ -0,0 +1,93 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2, Params, Version,
+};
+
+use crate::errors::sdk::SDKError;
+
+/// Current Argon2id cost parameters for newly hashed passwords. Bumping these only affects new
+/// hashes; existing ones are flagged by [`needs_rehash`] and stay as stored until re-hashed.
+fn current_params() -> Params {
+    // 19 MiB memory cost, 2 iterations, 1 degree of parallelism: the OWASP-recommended Argon2id
+    // baseline, picked over scrypt/bcrypt for its resistance to GPU/ASIC cracking.
+    Params::new(19 * 1024, 2, 1, None).expect("static argon2 params are valid")
+}
+
+fn hasher() -> Argon2<'static> {
+    Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, current_params())
+}
+
+/// Hashes `password` with a fresh random salt, returning a PHC-format string
+/// (e.g. `$argon2id$v=19$m=19456,t=2,p=1$...`) suitable for storing in `members.password_hash`.
+pub fn hash_password(password: &str) -> Result<String, SDKError> {
+    let salt = SaltString::generate(&mut OsRng);
+
+    let hash = hasher()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|err| SDKError::ValidationError(format!("failed to hash password: {err}")))?;
+
+    Ok(hash.to_string())
+}
+
+/// Verifies `candidate` against a stored PHC hash in constant time.
+pub fn verify_password(candidate: &str, phc_hash: &str) -> Result<bool, SDKError> {
+    let parsed = PasswordHash::new(phc_hash).map_err(|err| SDKError::ValidationError(format!("invalid stored password hash: {err}")))?;
+
+    match hasher().verify_password(candidate.as_bytes(), &parsed) {
+        Ok(()) => Ok(true),
+        Err(argon2::password_hash::Error::Password) => Ok(false),
+        Err(err) => Err(SDKError::ValidationError(format!("failed to verify password: {err}"))),
+    }
+}
+
+/// Returns `true` if `phc_hash` cannot be parsed or its memory, time or parallelism cost differs
+/// from [`current_params`] (weaker or stronger), so
+/// callers can re-hash the plaintext (right after a successful [`verify_password`]) and persist
+/// the upgraded hash without forcing a separate migration.
+pub fn needs_rehash(phc_hash: &str) -> bool {
+    let Ok(parsed) = PasswordHash::new(phc_hash) else {
+        return true;
+    };
+
+    match Params::try_from(&parsed) {
+        Ok(params) => {
+            let current = current_params();
+            params.m_cost() != current.m_cost() || params.t_cost() != current.t_cost() || params.p_cost() != current.p_cost()
+        }
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_then_verify_round_trips() {
+        let hash = hash_password("correct horse battery staple").unwrap();
+
+        assert!(verify_password("correct horse battery staple", &hash).unwrap());
+        assert!(!verify_password("wrong password", &hash).unwrap());
+    }
+
+    #[test]
+    fn hash_uses_a_fresh_salt_each_time() {
+        let first = hash_password("same password").unwrap();
+        let second = hash_password("same password").unwrap();
+
+        assert_ne!(first, second, "two hashes of the same password must not be identical");
+        assert!(verify_password("same password", &first).unwrap());
+        assert!(verify_password("same password", &second).unwrap());
+    }
+
+    #[test]
+    fn needs_rehash_is_false_for_a_hash_just_produced_with_current_params() {
+        let hash = hash_password("whatever").unwrap();
+        assert!(!needs_rehash(&hash));
+    }
+
+    #[test]
+    fn needs_rehash_is_true_for_garbage_input() {
+        assert!(needs_rehash("not a real phc hash"));
+    }
+}
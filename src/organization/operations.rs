@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::{
+    backend::v2::{Engine, WithoutContext},
+    errors::sdk::SDKError,
+};
+
+/// Name of the settings row each organization stores its [`CreateOrganizationInput`] under,
+/// serialized as JSON. One per owner today.
+pub const GLOBAL_ORGANIZATION_SETTINGS_NAME: &str = "organization";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOrganizationInput {
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Organization {
+    pub owner_id: Uuid,
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct SetOrganizationInput {
+    pub owner_id: Uuid,
+    pub name: String,
+    pub value: String,
+}
+
+#[async_trait]
+pub trait OrganizationCrudOperations {
+    async fn set_organization_setting(&self, input: SetOrganizationInput) -> Result<Organization, SDKError>;
+}
+
+/// Upserts an organization setting row through `executor` (pool or open transaction), so callers
+/// can group it with other writes — see [`crate::backend::v2::Engine::transaction`].
+pub async fn set_organization_setting_with<'c, E>(executor: E, input: SetOrganizationInput) -> Result<Organization, SDKError>
+where
+    E: sqlx::Executor<'c, Database = Postgres>,
+{
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO organization_settings (owner_id, name, value)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (owner_id, name) DO UPDATE SET value = EXCLUDED.value
+        RETURNING owner_id, name, value
+        "#,
+        input.owner_id,
+        input.name,
+        input.value,
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(Organization { owner_id: row.owner_id, name: row.name, value: row.value })
+}
+
+#[async_trait]
+impl OrganizationCrudOperations for Engine<WithoutContext> {
+    #[tracing::instrument(name = "set_organization_setting", skip(self, input), fields(owner_id = %input.owner_id), err)]
+    async fn set_organization_setting(&self, input: SetOrganizationInput) -> Result<Organization, SDKError> {
+        set_organization_setting_with(self.db_pool.as_ref(), input).await
+    }
+}